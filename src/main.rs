@@ -1,10 +1,17 @@
 extern crate rustml;
+#[cfg(test)]
+#[macro_use]
+extern crate proptest;
 
 mod sparse_symmetric_matrix;
 mod sparse_row_matrix;
+mod sparse_column_matrix;
 mod vector;
+mod validity;
 mod preconditioner;
 mod solver;
+#[cfg(test)]
+mod spd_strategies;
 
 use sparse_symmetric_matrix::{SparseSymmetricMatrix, Entry};
 use vector::Vector;
@@ -20,10 +27,10 @@ fn main() {
     let v: Vector = Vector(vec![5.0, 6.0, 7.0]);
     let result = solver(&m, &v);
     assert_eq!(result.completed, true);
-    assert_eq!(result.iterations, 2);
-    assert_eq!(result.best_guess.0[0], 1.1666674087694608);
-    assert_eq!(result.best_guess.0[1], 0.0833110800778692);
-    assert_eq!(result.best_guess.0[2], 0.5694629884317245);
+    assert_eq!(result.iterations, 3);
+    assert_eq!(result.best_guess.0[0], 1.1666666666666665);
+    assert_eq!(result.best_guess.0[1], 0.08333333333333368);
+    assert_eq!(result.best_guess.0[2], 0.5694444444444441);
 }
 
 #[cfg(test)]
@@ -0,0 +1,194 @@
+use std::vec::Vec;
+
+use sparse_symmetric_matrix::SparseSymmetricMatrix;
+use vector::Vector;
+use validity::Validity;
+
+pub trait Preconditioner {
+    fn apply(&self, residual: &Vector) -> Vector;
+}
+
+// `SparseSymmetricMatrix` only stores structural nonzeros, so a diagonal
+// that was never assembled reads back as an implicit zero rather than
+// an error.
+fn diagonal_of(matrix: &SparseSymmetricMatrix, i: usize) -> f64 {
+    match matrix.indices[i].iter().position(|&c| c == i) {
+        Some(position) => matrix.values[i][position],
+        None => 0.0,
+    }
+}
+
+// M = I. Always valid; applying it returns the residual unchanged.
+pub struct IdentityPreconditioner;
+
+impl Preconditioner for IdentityPreconditioner {
+    fn apply(&self, residual: &Vector) -> Vector {
+        Vector(residual.0.clone())
+    }
+}
+
+// Jacobi preconditioner: M = diag(A).
+pub struct DiagonalPreconditioner {
+    inverse_diagonal: Vec<f64>,
+}
+
+impl DiagonalPreconditioner {
+    pub fn new(matrix: &SparseSymmetricMatrix) -> DiagonalPreconditioner {
+        let inverse_diagonal = (0..matrix.length + 1)
+            .map(|i| {
+                let d = diagonal_of(matrix, i);
+                // a zero (or never-assembled) diagonal can't be scaled
+                // by; leave that row unscaled rather than dividing by
+                // zero and poisoning the solve with infinities.
+                if d == 0.0 { 1.0 } else { 1.0 / d }
+            })
+            .collect();
+        DiagonalPreconditioner { inverse_diagonal }
+    }
+}
+
+impl Preconditioner for DiagonalPreconditioner {
+    fn apply(&self, residual: &Vector) -> Vector {
+        Vector(
+            residual
+                .0
+                .iter()
+                .zip(&self.inverse_diagonal)
+                .map(|(r, d)| r * d)
+                .collect(),
+        )
+    }
+}
+
+// IC(0): a Cholesky factor L that keeps exactly the sparsity pattern of
+// the lower triangle of `A`, discarding any fill that would fall outside
+// it. Stored row-major (CSR-like), with each row's entries sorted by
+// column and ending in the diagonal, so both the forward solve `Ly = r`
+// and the transpose-free back solve `L^T z = y` can walk it directly.
+pub struct IncompleteCholeskyPreconditioner {
+    valid: bool,
+    values: Vec<f64>,
+    column_index: Vec<usize>,
+    row_pointers: Vec<usize>,
+}
+
+fn sparse_dot(a: &[(usize, f64)], b: &[(usize, f64)]) -> f64 {
+    let mut i = 0;
+    let mut j = 0;
+    let mut sum = 0.0;
+    while i < a.len() && j < b.len() {
+        if a[i].0 == b[j].0 {
+            sum += a[i].1 * b[j].1;
+            i += 1;
+            j += 1;
+        } else if a[i].0 < b[j].0 {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    sum
+}
+
+impl IncompleteCholeskyPreconditioner {
+    pub fn new(matrix: &SparseSymmetricMatrix) -> IncompleteCholeskyPreconditioner {
+        let n = matrix.length + 1;
+        let mut rows: Vec<Vec<(usize, f64)>> = vec![vec![]; n];
+        let mut valid = true;
+
+        'columns: for k in 0..n {
+            let a_kk = diagonal_of(matrix, k);
+            let below_diagonal = &rows[k][..];
+            let d = a_kk - sparse_dot(below_diagonal, below_diagonal);
+            if d <= 0.0 {
+                valid = false;
+                break 'columns;
+            }
+            let l_kk = d.sqrt();
+            rows[k].push((k, l_kk));
+
+            for (column, a_ik) in matrix.indices[k]
+                .iter()
+                .zip(&matrix.values[k])
+                .filter(|&(&i, _)| i > k)
+                .map(|(&i, &v)| (i, v))
+            {
+                let dot = {
+                    let (left, right) = (&rows[column][..], &rows[k][..rows[k].len() - 1]);
+                    sparse_dot(left, right)
+                };
+                let l_ik = (a_ik - dot) / l_kk;
+                rows[column].push((k, l_ik));
+            }
+        }
+
+        let mut values = vec![];
+        let mut column_index = vec![];
+        let mut row_pointers = vec![0];
+        for row in &rows {
+            for &(column, value) in row {
+                values.push(value);
+                column_index.push(column);
+            }
+            row_pointers.push(values.len());
+        }
+
+        IncompleteCholeskyPreconditioner {
+            valid,
+            values,
+            column_index,
+            row_pointers,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.row_pointers.len() - 1
+    }
+
+    fn forward_substitute(&self, r: &Vector) -> Vector {
+        let n = self.len();
+        let mut y = vec![0.0; n];
+        for i in 0..n {
+            let start = self.row_pointers[i];
+            let end = self.row_pointers[i + 1];
+            let sum: f64 = (start..end - 1)
+                .map(|idx| self.values[idx] * y[self.column_index[idx]])
+                .sum();
+            let diagonal = self.values[end - 1];
+            y[i] = (r.0[i] - sum) / diagonal;
+        }
+        Vector(y)
+    }
+
+    // solves L^T z = y directly off L's row storage, avoiding an explicit
+    // transpose: walking rows back to front, each row's off-diagonal
+    // entries are exactly the column contributions of L^T's later rows.
+    fn back_substitute(&self, y: &Vector) -> Vector {
+        let n = self.len();
+        let mut z = y.0.clone();
+        for k in (0..n).rev() {
+            let start = self.row_pointers[k];
+            let end = self.row_pointers[k + 1];
+            let diagonal = self.values[end - 1];
+            z[k] /= diagonal;
+            for idx in start..end - 1 {
+                let i = self.column_index[idx];
+                z[i] -= self.values[idx] * z[k];
+            }
+        }
+        Vector(z)
+    }
+}
+
+impl Preconditioner for IncompleteCholeskyPreconditioner {
+    fn apply(&self, residual: &Vector) -> Vector {
+        let y = self.forward_substitute(residual);
+        self.back_substitute(&y)
+    }
+}
+
+impl Validity for IncompleteCholeskyPreconditioner {
+    fn is_valid(&self) -> bool {
+        self.valid
+    }
+}
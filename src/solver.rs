@@ -0,0 +1,215 @@
+use sparse_row_matrix::SparseRowMatrix;
+use sparse_symmetric_matrix::SparseSymmetricMatrix;
+use vector::Vector;
+use preconditioner::{Preconditioner, DiagonalPreconditioner, IncompleteCholeskyPreconditioner};
+use validity::Validity;
+
+const MAX_ITERATIONS: usize = 1000;
+const TOLERANCE: f64 = 1e-10;
+
+pub struct SolverResult {
+    pub completed: bool,
+    pub iterations: usize,
+    pub best_guess: Vector,
+}
+
+// preconditioned conjugate gradient. Prefers IC(0) since it tends to cut
+// iteration counts dramatically on ill-conditioned systems, but falls
+// back to the always-valid diagonal preconditioner if IC(0) breaks down
+// (non-positive pivot).
+pub fn solver(matrix: &SparseSymmetricMatrix, b: &Vector) -> SolverResult {
+    let ic = IncompleteCholeskyPreconditioner::new(matrix);
+    let preconditioner: Box<Preconditioner> = if ic.is_valid() {
+        Box::new(ic)
+    } else {
+        Box::new(DiagonalPreconditioner::new(matrix))
+    };
+
+    let a = SparseRowMatrix::new(matrix);
+    let n = b.0.len();
+    let mut x = Vector::zeros(n);
+    let mut r = b.sub(&a.apply(&x));
+    let mut z = preconditioner.apply(&r);
+    let mut p = Vector(z.0.clone());
+    let mut rz = r.dot(&z);
+
+    let mut iterations = 0;
+    let mut completed = r.norm() < TOLERANCE;
+
+    while !completed && iterations < MAX_ITERATIONS {
+        let ap = a.apply(&p);
+        let alpha = rz / p.dot(&ap);
+        x = x.add_scaled(alpha, &p);
+        r = r.sub(&ap.scale(alpha));
+        iterations += 1;
+
+        if r.norm() < TOLERANCE {
+            completed = true;
+            break;
+        }
+
+        z = preconditioner.apply(&r);
+        let rz_new = r.dot(&z);
+        let beta = rz_new / rz;
+        p = z.add_scaled(beta, &p);
+        rz = rz_new;
+    }
+
+    SolverResult {
+        completed,
+        iterations,
+        best_guess: x,
+    }
+}
+
+// fraction of a row's largest-magnitude entry a candidate pivot must
+// reach to be considered numerically acceptable.
+const PIVOT_THRESHOLD: f64 = 0.1;
+
+// direct solve via sparse LU with Markowitz-style pivoting, for the
+// smaller or non-SPD systems where `solver` above can stall or doesn't
+// apply. Works on a dense n x n intermediate (`SparseSymmetricMatrix`
+// already holds every entry, not just the lower triangle, so it doubles
+// as a general sparse matrix here) - acceptable for the modest problem
+// sizes this path targets, while `L`/`U` themselves are kept sparse.
+pub fn solve_direct(matrix: &SparseSymmetricMatrix, b: &Vector) -> Vector {
+    let n = matrix.length + 1;
+    let mut w = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        for (position, &j) in matrix.indices[i].iter().enumerate() {
+            w[i][j] = matrix.values[i][position];
+        }
+    }
+
+    let mut row_permutation: Vec<usize> = (0..n).collect();
+    let mut column_permutation: Vec<usize> = (0..n).collect();
+    let mut lower: Vec<Vec<(usize, f64)>> = vec![vec![]; n];
+    let mut upper: Vec<Vec<(usize, f64)>> = vec![vec![]; n];
+
+    for k in 0..n {
+        let row_max: Vec<f64> = row_permutation[k..]
+            .iter()
+            .map(|&row| {
+                column_permutation[k..]
+                    .iter()
+                    .fold(0.0, |acc, &column| f64::max(acc, w[row][column].abs()))
+            })
+            .collect();
+        let row_nnz: Vec<usize> = row_permutation[k..]
+            .iter()
+            .map(|&row| {
+                column_permutation[k..]
+                    .iter()
+                    .filter(|&&column| w[row][column] != 0.0)
+                    .count()
+            })
+            .collect();
+        let column_nnz: Vec<usize> = column_permutation[k..]
+            .iter()
+            .map(|&column| {
+                row_permutation[k..]
+                    .iter()
+                    .filter(|&&row| w[row][column] != 0.0)
+                    .count()
+            })
+            .collect();
+
+        let mut best: Option<(usize, usize, usize)> = None;
+        for (i, &row) in row_permutation[k..].iter().enumerate() {
+            if row_max[i] == 0.0 {
+                continue;
+            }
+            for (j, &column) in column_permutation[k..].iter().enumerate() {
+                let value = w[row][column];
+                if value == 0.0 || value.abs() < PIVOT_THRESHOLD * row_max[i] {
+                    continue;
+                }
+                let score = (row_nnz[i] - 1) * (column_nnz[j] - 1);
+                if best.map_or(true, |(best_score, _, _)| score < best_score) {
+                    best = Some((score, i, j));
+                }
+            }
+        }
+        let (_, best_i, best_j) = best.expect("matrix is singular to working precision");
+
+        row_permutation.swap(k, k + best_i);
+        lower.swap(k, k + best_i);
+        column_permutation.swap(k, k + best_j);
+
+        let pivot_row = row_permutation[k];
+        let pivot_column = column_permutation[k];
+        let pivot_value = w[pivot_row][pivot_column];
+
+        for (step, &row) in row_permutation[k + 1..].iter().enumerate() {
+            let factor = w[row][pivot_column] / pivot_value;
+            if factor == 0.0 {
+                continue;
+            }
+            lower[k + 1 + step].push((k, factor));
+            for &column in &column_permutation[k..] {
+                w[row][column] -= factor * w[pivot_row][column];
+            }
+        }
+    }
+
+    // read U off only once the column permutation is final, so later
+    // column swaps can't leave its positions pointing at the wrong column.
+    for k in 0..n {
+        let pivot_row = row_permutation[k];
+        for (step, &column) in column_permutation[k..].iter().enumerate() {
+            let value = w[pivot_row][column];
+            if value != 0.0 {
+                upper[k].push((k + step, value));
+            }
+        }
+    }
+
+    let mut y = vec![0.0; n];
+    for k in 0..n {
+        let sum: f64 = lower[k].iter().map(|&(j, value)| value * y[j]).sum();
+        y[k] = b.0[row_permutation[k]] - sum;
+    }
+
+    let mut x = vec![0.0; n];
+    for k in (0..n).rev() {
+        let sum: f64 = upper[k]
+            .iter()
+            .filter(|&&(j, _)| j > k)
+            .map(|&(j, value)| value * x[j])
+            .sum();
+        let diagonal = upper[k]
+            .iter()
+            .find(|&&(j, _)| j == k)
+            .map(|&(_, value)| value)
+            .expect("eliminated row is missing its pivot");
+        x[k] = (y[k] - sum) / diagonal;
+    }
+
+    let mut result = vec![0.0; n];
+    for k in 0..n {
+        result[column_permutation[k]] = x[k];
+    }
+    Vector(result)
+}
+
+#[test]
+fn test_solve_direct_matches_sparse_row_matrix_apply() {
+    use sparse_symmetric_matrix::Entry;
+
+    let m = SparseSymmetricMatrix::new(&vec![
+        Entry { x: 0, y: 0, v: 4.0 },
+        Entry { x: 0, y: 1, v: 1.0 },
+        Entry { x: 1, y: 1, v: 3.0 },
+        Entry { x: 1, y: 2, v: 1.0 },
+        Entry { x: 2, y: 2, v: 5.0 },
+    ]);
+    let b = Vector(vec![1.0, 2.0, 3.0]);
+
+    let x = solve_direct(&m, &b);
+
+    let a = SparseRowMatrix::new(&m);
+    let reconstructed = a.apply(&x);
+    for (expected, actual) in b.0.iter().zip(&reconstructed.0) {
+        assert!((expected - actual).abs() < 1e-8);
+    }
+}
@@ -0,0 +1,188 @@
+use std::vec::Vec;
+use std::fmt;
+
+use vector::Vector;
+use sparse_symmetric_matrix::SparseSymmetricMatrix;
+use sparse_row_matrix::SparseRowMatrix;
+use validity::Validity;
+
+// compressed-sparse-column companion to `SparseRowMatrix`: the natural
+// layout when the matrix-vector product you care about is `A^T * x`
+// rather than `A * x`.
+pub struct SparseColumnMatrix {
+    pub(crate) values: Vec<f64>,
+    pub(crate) row_index: Vec<usize>,
+    pub(crate) column_pointers: Vec<usize>,
+}
+
+impl SparseColumnMatrix {
+    pub fn new(matrix: &SparseSymmetricMatrix) -> SparseColumnMatrix {
+        SparseColumnMatrix::from(&SparseRowMatrix::new(matrix))
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.column_pointers.len() - 1
+    }
+
+    // A * rhs. Scatters into `result` one column at a time; prefer
+    // `SparseRowMatrix::apply` when you need this often.
+    pub fn apply(&self, rhs: &Vector) -> Vector {
+        assert_eq!(self.len(), rhs.0.len());
+        assert!(self.is_valid());
+        let n = self.len();
+        let mut result = vec![0.0; n];
+        for column in 0..n {
+            let x = self.column_pointers[column];
+            let y = self.column_pointers[column + 1];
+            for k in x..y {
+                let row = self.row_index[k];
+                result[row] += self.values[k] * rhs.0[column];
+            }
+        }
+        Vector(result)
+    }
+
+    // A^T * rhs, one dot product per column - the efficient direction
+    // for this layout, mirroring `SparseRowMatrix::apply`.
+    pub fn apply_transpose(&self, rhs: &Vector) -> Vector {
+        assert_eq!(self.len(), rhs.0.len());
+        assert!(self.is_valid());
+        let n = self.len();
+        let mut result = vec![0.0; n];
+        for column in 0..n {
+            let x = self.column_pointers[column];
+            let y = self.column_pointers[column + 1];
+            for k in x..y {
+                let row = self.row_index[k];
+                result[column] += self.values[k] * rhs.0[row];
+            }
+        }
+        Vector(result)
+    }
+}
+
+impl<'a> From<&'a SparseSymmetricMatrix> for SparseColumnMatrix {
+    fn from(matrix: &'a SparseSymmetricMatrix) -> SparseColumnMatrix {
+        SparseColumnMatrix::new(matrix)
+    }
+}
+
+impl<'a> From<&'a SparseRowMatrix> for SparseColumnMatrix {
+    fn from(matrix: &'a SparseRowMatrix) -> SparseColumnMatrix {
+        let n = matrix.len();
+        let mut columns: Vec<Vec<(usize, f64)>> = vec![vec![]; n];
+        for row in 0..n {
+            let x = matrix.row_pointers[row];
+            let y = matrix.row_pointers[row + 1];
+            for k in x..y {
+                let column = matrix.column_index[k];
+                columns[column].push((row, matrix.values[k]));
+            }
+        }
+
+        let mut values = vec![];
+        let mut row_index = vec![];
+        let mut column_pointers = vec![0];
+        for column in &mut columns {
+            column.sort_by_key(|&(row, _)| row);
+            for &(row, value) in column.iter() {
+                values.push(value);
+                row_index.push(row);
+            }
+            column_pointers.push(values.len());
+        }
+
+        SparseColumnMatrix {
+            values,
+            row_index,
+            column_pointers,
+        }
+    }
+}
+
+impl Validity for SparseColumnMatrix {
+    fn is_valid(&self) -> bool {
+        self.values
+            .iter()
+            .filter(|e| !e.is_finite())
+            .collect::<Vec<&f64>>()
+            .len() == 0
+    }
+}
+
+impl fmt::Debug for SparseColumnMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        assert!(self.is_valid());
+        let n = self.len();
+        let mut rows = vec![];
+        let mut columns = vec![];
+        let mut values = vec![];
+        for column in 0..n {
+            let x = self.column_pointers[column];
+            let y = self.column_pointers[column + 1];
+            for k in x..y {
+                rows.push(self.row_index[k] + 1);
+                columns.push(column + 1);
+                values.push(self.values[k]);
+            }
+        }
+        writeln!(f, "sparse({:?},...", rows)?;
+        writeln!(f, "       {:?},...", columns)?;
+        write!(f, "       {:?}, {}, {})", values, n, n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sparse_symmetric_matrix::Entry;
+
+    fn sample_matrix() -> SparseSymmetricMatrix {
+        SparseSymmetricMatrix::new(&vec![
+            Entry { x: 0, y: 0, v: 1.0 },
+            Entry { x: 0, y: 1, v: 2.0 },
+            Entry { x: 0, y: 2, v: 3.0 },
+            Entry { x: 1, y: 1, v: 5.0 },
+            Entry { x: 1, y: 2, v: 6.0 },
+            Entry { x: 2, y: 2, v: 9.0 },
+        ])
+    }
+
+    #[test]
+    fn test_round_trip_csr_csc_csr() {
+        let m = sample_matrix();
+        let csr = SparseRowMatrix::new(&m);
+        let csc = SparseColumnMatrix::from(&csr);
+        let round_tripped = SparseRowMatrix::from(&csc);
+
+        assert_eq!(csr.values, round_tripped.values);
+        assert_eq!(csr.column_index, round_tripped.column_index);
+        assert_eq!(csr.row_pointers, round_tripped.row_pointers);
+    }
+
+    #[test]
+    fn test_csc_apply_matches_csr_apply() {
+        let m = sample_matrix();
+        let csr = SparseRowMatrix::new(&m);
+        let csc = SparseColumnMatrix::new(&m);
+        let v = Vector(vec![3.0, 2.0, 1.0]);
+
+        let from_csr = csr.apply(&v);
+        let from_csc = csc.apply(&v);
+
+        assert_eq!(from_csr.0, from_csc.0);
+    }
+
+    #[test]
+    fn test_csc_apply_transpose_matches_csr_apply_transpose() {
+        let m = sample_matrix();
+        let csr = SparseRowMatrix::new(&m);
+        let csc = SparseColumnMatrix::new(&m);
+        let v = Vector(vec![3.0, 2.0, 1.0]);
+
+        let from_csr = csr.apply_transpose(&v);
+        let from_csc = csc.apply_transpose(&v);
+
+        assert_eq!(from_csr.0, from_csc.0);
+    }
+}
@@ -3,14 +3,15 @@ use std::fmt;
 
 use vector::Vector;
 use sparse_symmetric_matrix::SparseSymmetricMatrix;
+use sparse_column_matrix::SparseColumnMatrix;
 use validity::Validity;
 
 // we use this structure only for multiplication as it is more
 // efficient for this purpose than SparseSymmetricMatrix.
 pub struct SparseRowMatrix {
-    values: Vec<f64>,
-    column_index: Vec<usize>,
-    row_pointers: Vec<usize>,
+    pub(crate) values: Vec<f64>,
+    pub(crate) column_index: Vec<usize>,
+    pub(crate) row_pointers: Vec<usize>,
 }
 
 impl SparseRowMatrix {
@@ -34,7 +35,7 @@ impl SparseRowMatrix {
         }
     }
 
-    fn len(&self) -> usize {
+    pub(crate) fn len(&self) -> usize {
         return self.row_pointers.len() - 1;
     }
 
@@ -54,6 +55,64 @@ impl SparseRowMatrix {
         }
         Vector(result)
     }
+
+    // A^T * rhs. Less cache-friendly than `apply` since it scatters into
+    // `result` instead of accumulating a single row at a time; prefer
+    // `SparseColumnMatrix::apply_transpose` when you need this often.
+    pub fn apply_transpose(&self, rhs: &Vector) -> Vector {
+        assert_eq!(self.len(), rhs.0.len());
+        assert!(self.is_valid());
+        let n = self.len();
+        let mut result = vec![0.0; n];
+        for i in 0..n {
+            let x = self.row_pointers[i];
+            let y = self.row_pointers[i + 1];
+            for j in x..y {
+                let index = self.column_index[j];
+                result[index] += self.values[j] * rhs.0[i];
+            }
+        }
+        Vector(result)
+    }
+}
+
+impl<'a> From<&'a SparseSymmetricMatrix> for SparseRowMatrix {
+    fn from(matrix: &'a SparseSymmetricMatrix) -> SparseRowMatrix {
+        SparseRowMatrix::new(matrix)
+    }
+}
+
+impl<'a> From<&'a SparseColumnMatrix> for SparseRowMatrix {
+    fn from(matrix: &'a SparseColumnMatrix) -> SparseRowMatrix {
+        let n = matrix.len();
+        let mut rows: Vec<Vec<(usize, f64)>> = vec![vec![]; n];
+        for column in 0..n {
+            let x = matrix.column_pointers[column];
+            let y = matrix.column_pointers[column + 1];
+            for k in x..y {
+                let row = matrix.row_index[k];
+                rows[row].push((column, matrix.values[k]));
+            }
+        }
+
+        let mut values = vec![];
+        let mut column_index = vec![];
+        let mut row_pointers = vec![0];
+        for row in &mut rows {
+            row.sort_by_key(|&(column, _)| column);
+            for &(column, value) in row.iter() {
+                values.push(value);
+                column_index.push(column);
+            }
+            row_pointers.push(values.len());
+        }
+
+        SparseRowMatrix {
+            values,
+            column_index,
+            row_pointers,
+        }
+    }
 }
 
 impl Validity for SparseRowMatrix {
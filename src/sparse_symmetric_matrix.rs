@@ -1,6 +1,9 @@
 use std::vec::Vec;
 use std::cmp::{min, max};
 use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
 
 use validity::Validity;
 
@@ -53,14 +56,25 @@ impl SparseSymmetricMatrix {
         } else {
             a.x.cmp(&b.x)
         });
-        sorted_entries.dedup_by(|a, b| a.x == b.x && a.y == b.y);
-        let length = sorted_entries.iter().fold(
+        // the COO convention (and Matrix Market's) is that repeated
+        // coordinates are summed when assembling a matrix, which finite-
+        // element and graph-Laplacian assembly both rely on. Merge
+        // adjacent equal-coordinate entries instead of keeping only the
+        // first, as a plain `dedup_by` would.
+        let mut summed_entries: Vec<Entry> = vec![];
+        for entry in sorted_entries {
+            match summed_entries.last_mut() {
+                Some(last) if last.x == entry.x && last.y == entry.y => last.v += entry.v,
+                _ => summed_entries.push(entry),
+            }
+        }
+        let length = summed_entries.iter().fold(
             0,
             |acc, e| max(acc, max(e.x, e.y)),
         );
         let mut indices = vec![vec![]; length + 1];
         let mut values = vec![vec![]; length + 1];
-        for entry in sorted_entries {
+        for entry in summed_entries {
             indices[entry.x].push(entry.y);
             values[entry.x].push(entry.v);
         }
@@ -70,6 +84,78 @@ impl SparseSymmetricMatrix {
             values,
         }
     }
+
+    // parses the coordinate real symmetric flavour of the Matrix Market
+    // format: an optional `%%MatrixMarket` banner, any number of `%`
+    // comment lines, a `rows cols nnz` size line, then `nnz` "i j value"
+    // data lines with 1-based indices.
+    pub fn from_matrix_market<P: AsRef<Path>>(path: P) -> io::Result<SparseSymmetricMatrix> {
+        let file = File::open(path)?;
+        let reader = BufReader::new(file);
+        let mut entries = vec![];
+        let mut size_seen = false;
+        for line in reader.lines() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('%') {
+                continue;
+            }
+            if !size_seen {
+                size_seen = true;
+                continue;
+            }
+            let fields = line.split_whitespace().collect::<Vec<&str>>();
+            if fields.len() < 3 {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("expected \"i j value\", got {:?}", line),
+                ));
+            }
+            let parse_error = |field: &str| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("not a number: {:?}", field),
+                )
+            };
+            let i = fields[0].parse::<usize>().map_err(|_| parse_error(fields[0]))?;
+            let j = fields[1].parse::<usize>().map_err(|_| parse_error(fields[1]))?;
+            let v = fields[2].parse::<f64>().map_err(|_| parse_error(fields[2]))?;
+            if i == 0 || j == 0 {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "Matrix Market indices are 1-based",
+                ));
+            }
+            entries.push(Entry {
+                x: i - 1,
+                y: j - 1,
+                v: v,
+            });
+        }
+        Ok(SparseSymmetricMatrix::new(&entries))
+    }
+
+    // writes the lower triangle in coordinate real symmetric Matrix
+    // Market format, mirroring `from_matrix_market`.
+    pub fn to_matrix_market<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut file = File::create(path)?;
+        writeln!(file, "%%MatrixMarket matrix coordinate real symmetric")?;
+        let n = self.length + 1;
+        let mut lines = vec![];
+        for i in 0..n {
+            for j in 0..self.indices[i].len() {
+                let column = self.indices[i][j];
+                if i >= column {
+                    lines.push((i, column, self.values[i][j]));
+                }
+            }
+        }
+        writeln!(file, "{} {} {}", n, n, lines.len())?;
+        for (i, j, v) in lines {
+            writeln!(file, "{} {} {}", i + 1, j + 1, v)?;
+        }
+        Ok(())
+    }
 }
 
 impl Validity for SparseSymmetricMatrix {
@@ -153,17 +239,28 @@ fn test_mixed_construct() {
 }
 
 #[test]
-fn test_duplicate_construct() {
+fn test_diagonal_duplicate_construct_sums_values() {
     let m = SparseSymmetricMatrix::new(&vec![
-        Entry { x: 0, y: 1, v: 2.0 },
         Entry { x: 0, y: 0, v: 1.0 },
         Entry { x: 1, y: 1, v: 5.0 },
-        Entry { x: 2, y: 1, v: 6.0 },
+        Entry { x: 1, y: 1, v: 1.0 },
+    ]);
+    assert!(m.is_valid());
+    assert_eq!(m.length, 1);
+    assert_eq!(m.indices[1], vec![1]);
+    assert_eq!(m.values[1], vec![6.0]);
+}
+
+#[test]
+fn test_off_diagonal_duplicate_construct_sums_values() {
+    let m = SparseSymmetricMatrix::new(&vec![
+        Entry { x: 0, y: 0, v: 1.0 },
+        Entry { x: 0, y: 1, v: 2.0 },
+        Entry { x: 0, y: 1, v: 0.5 },
         Entry { x: 1, y: 1, v: 5.0 },
+        Entry { x: 1, y: 2, v: 6.0 },
         Entry { x: 0, y: 2, v: 3.0 },
         Entry { x: 2, y: 2, v: 9.0 },
-        Entry { x: 2, y: 2, v: 9.0 },
-        Entry { x: 2, y: 0, v: 3.0 },
     ]);
     assert!(m.is_valid());
     assert_eq!(m.length, 2);
@@ -171,8 +268,8 @@ fn test_duplicate_construct() {
     assert_eq!(
         m.values,
         vec![
-            vec![1.0, 2.0, 3.0],
-            vec![2.0, 5.0, 6.0],
+            vec![1.0, 2.5, 3.0],
+            vec![2.5, 5.0, 6.0],
             vec![3.0, 6.0, 9.0],
         ]
     );
@@ -199,3 +296,44 @@ fn test_sparse_construct() {
     assert_eq!(m.values[8][0], 9.0);
     assert_eq!(m.values[10][0], 10.0);
 }
+
+#[test]
+fn test_matrix_market_round_trip() {
+    let m = SparseSymmetricMatrix::new(&vec![
+        Entry { x: 0, y: 0, v: 1.0 },
+        Entry { x: 0, y: 1, v: 2.0 },
+        Entry { x: 0, y: 2, v: 3.0 },
+        Entry { x: 1, y: 1, v: 5.0 },
+        Entry { x: 1, y: 2, v: 6.0 },
+        Entry { x: 2, y: 2, v: 9.0 },
+    ]);
+    let path = std::env::temp_dir().join("pcgs_test_round_trip.mtx");
+    m.to_matrix_market(&path).unwrap();
+    let loaded = SparseSymmetricMatrix::from_matrix_market(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.length, m.length);
+    assert_eq!(loaded.indices, m.indices);
+    assert_eq!(loaded.values, m.values);
+}
+
+#[test]
+fn test_from_matrix_market_parses_banner_and_comments() {
+    let path = std::env::temp_dir().join("pcgs_test_banner.mtx");
+    {
+        let mut file = File::create(&path).unwrap();
+        writeln!(file, "%%MatrixMarket matrix coordinate real symmetric").unwrap();
+        writeln!(file, "% generated for a test").unwrap();
+        writeln!(file, "3 3 4").unwrap();
+        writeln!(file, "1 1 1.0").unwrap();
+        writeln!(file, "1 2 2.0").unwrap();
+        writeln!(file, "1 3 3.0").unwrap();
+        writeln!(file, "").unwrap();
+        writeln!(file, "3 3 9.0").unwrap();
+    }
+    let m = SparseSymmetricMatrix::from_matrix_market(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(m.is_valid());
+    assert_eq!(m.length, 2);
+    assert_eq!(m.indices[0], vec![0, 1, 2]);
+    assert_eq!(m.values[0], vec![1.0, 2.0, 3.0]);
+}
@@ -0,0 +1,59 @@
+// test-only proptest strategies for generating random symmetric
+// positive-definite matrices, so the solver and preconditioners can be
+// fuzzed instead of relying on a handful of hardcoded 3x3 cases.
+use std::cmp::{min, max};
+
+use proptest::prelude::*;
+
+use sparse_symmetric_matrix::{SparseSymmetricMatrix, Entry};
+use vector::Vector;
+
+// generates a random sparse SPD matrix together with a right-hand side
+// of matching dimension. SPD is forced by making the matrix diagonally
+// dominant: each diagonal entry is set to `1 + sum(|off-diagonal entries
+// in that row|)`, which guarantees positive-definiteness regardless of
+// what the off-diagonal entries are.
+pub fn spd_matrix_and_rhs() -> impl Strategy<Value = (SparseSymmetricMatrix, Vector)> {
+    (2usize..8usize)
+        .prop_flat_map(|n| {
+            let entries = prop::collection::vec((0..n, 0..n, -5.0f64..5.0), 0..n * n);
+            let rhs = prop::collection::vec(-10.0f64..10.0, n);
+            (Just(n), entries, rhs)
+        })
+        .prop_map(|(n, raw_entries, rhs)| {
+            let mut off_diagonal_sum = vec![0.0; n];
+            let mut entries = vec![];
+            for (x, y, v) in raw_entries {
+                let (x, y) = (min(x, y), max(x, y));
+                if x == y {
+                    continue;
+                }
+                off_diagonal_sum[x] += v.abs();
+                off_diagonal_sum[y] += v.abs();
+                entries.push(Entry { x, y, v });
+            }
+            for i in 0..n {
+                entries.push(Entry {
+                    x: i,
+                    y: i,
+                    v: 1.0 + off_diagonal_sum[i],
+                });
+            }
+            (SparseSymmetricMatrix::new(&entries), Vector(rhs))
+        })
+}
+
+proptest! {
+    #[test]
+    fn solver_converges_on_random_spd_matrices((matrix, b) in spd_matrix_and_rhs()) {
+        use sparse_row_matrix::SparseRowMatrix;
+        use solver::solver;
+
+        let result = solver(&matrix, &b);
+        prop_assert!(result.completed);
+
+        let a = SparseRowMatrix::new(&matrix);
+        let residual = a.apply(&result.best_guess).sub(&b);
+        prop_assert!(residual.norm() < 1e-6);
+    }
+}
@@ -0,0 +1,5 @@
+// shared by anything that can end up holding NaN/infinite values after a
+// numerically sensitive computation (factorizations, solves, products).
+pub trait Validity {
+    fn is_valid(&self) -> bool;
+}
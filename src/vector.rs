@@ -0,0 +1,47 @@
+use std::vec::Vec;
+
+use validity::Validity;
+
+#[derive(Debug)]
+pub struct Vector(pub Vec<f64>);
+
+impl Vector {
+    pub fn zeros(length: usize) -> Vector {
+        Vector(vec![0.0; length])
+    }
+
+    pub fn dot(&self, rhs: &Vector) -> f64 {
+        assert_eq!(self.0.len(), rhs.0.len());
+        self.0.iter().zip(&rhs.0).map(|(a, b)| a * b).sum()
+    }
+
+    pub fn add(&self, rhs: &Vector) -> Vector {
+        assert_eq!(self.0.len(), rhs.0.len());
+        Vector(self.0.iter().zip(&rhs.0).map(|(a, b)| a + b).collect())
+    }
+
+    pub fn sub(&self, rhs: &Vector) -> Vector {
+        assert_eq!(self.0.len(), rhs.0.len());
+        Vector(self.0.iter().zip(&rhs.0).map(|(a, b)| a - b).collect())
+    }
+
+    pub fn scale(&self, s: f64) -> Vector {
+        Vector(self.0.iter().map(|a| a * s).collect())
+    }
+
+    // self + s * rhs
+    pub fn add_scaled(&self, s: f64, rhs: &Vector) -> Vector {
+        assert_eq!(self.0.len(), rhs.0.len());
+        Vector(self.0.iter().zip(&rhs.0).map(|(a, b)| a + s * b).collect())
+    }
+
+    pub fn norm(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+}
+
+impl Validity for Vector {
+    fn is_valid(&self) -> bool {
+        self.0.iter().filter(|e| !e.is_finite()).collect::<Vec<&f64>>().is_empty()
+    }
+}